@@ -2,7 +2,10 @@ use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
-#[command(name = "Rusty Journal", about = "A command line to-do app written in Rust")]
+#[command(
+    name = "Rusty Journal",
+    about = "A command line to-do app written in Rust"
+)]
 pub struct CommandLineArgs {
     #[command(subcommand)]
     pub action: Action,
@@ -25,11 +28,15 @@ pub enum Action {
         /// The due date for the task (optional).
         #[arg(short, long)]
         due_date: Option<String>,
+
+        /// Id of a task this one depends on (can be passed multiple times).
+        #[arg(long = "depends-on")]
+        depends_on: Vec<usize>,
     },
-    /// Remove an entry from the journal file by position.
+    /// Mark an entry in the journal file as done, by id.
     Done {
         #[arg()]
-        position: usize,
+        id: usize,
     },
     /// List all tasks in the journal file.
     List {
@@ -37,9 +44,26 @@ pub enum Action {
         #[arg(short, long)]
         category: Option<String>,
 
-        /// The sort order for the tasks (optional).
+        /// The sort order for the tasks: "asc", "desc", or "urgency" (optional).
         #[arg(short, long, default_value = "asc")]
         sort_order: String,
+
+        /// Filter tasks with a small query language, e.g. "priority:high,due<2024-12-31".
+        /// Clauses are joined with ',' and all must match (AND).
+        #[arg(short, long)]
+        filter: Option<String>,
+
+        /// Comma-separated subset of columns to print: id,text,created,due,priority,category,logged,urgency.
+        #[arg(long)]
+        columns: Option<String>,
+
+        /// Hide tasks that still have an incomplete dependency.
+        #[arg(long)]
+        ready: bool,
+
+        /// Which tasks to show: "pending", "done", or "all".
+        #[arg(long, default_value = "pending")]
+        status: String,
     },
     /// Search for tasks by keyword.
     Search {
@@ -47,4 +71,47 @@ pub enum Action {
         #[arg()]
         keyword: String,
     },
-}
\ No newline at end of file
+    /// Log time against an existing task by id.
+    /// OPTIONS:
+    ///     [--date "yyyy-mm-dd"]
+    Track {
+        /// The id of the task to log time against.
+        #[arg()]
+        id: usize,
+
+        /// Duration to log, e.g. "1h30m", "2h", or "45m".
+        #[arg()]
+        duration: String,
+
+        /// The date the time was logged (optional, defaults to today).
+        #[arg(long)]
+        date: Option<String>,
+    },
+    /// Add dependencies to an existing task; rejects edits that would create a cycle.
+    Depend {
+        /// The id of the task to edit.
+        #[arg()]
+        id: usize,
+
+        /// Id of a task it should depend on (can be passed multiple times).
+        #[arg(long = "on", required = true)]
+        depends_on: Vec<usize>,
+    },
+    /// Export the journal to a versioned, gzip-compressed tar bundle.
+    Export {
+        /// Path to write the bundle to.
+        #[arg()]
+        out: PathBuf,
+    },
+    /// Import tasks from a previously exported bundle, merging them into the journal.
+    Import {
+        /// Path to the bundle to import.
+        #[arg()]
+        bundle: PathBuf,
+    },
+    /// Clear the completion timestamp of an entry, returning it to the pending list, by id.
+    Restore {
+        #[arg()]
+        id: usize,
+    },
+}
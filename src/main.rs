@@ -28,18 +28,40 @@ fn main() -> anyhow::Result<()> {
         .ok_or(anyhow!("Failed to find journal file."))?;
 
     match action {
-        Add { task, due_date } => {
+        Add {
+            task,
+            due_date,
+            depends_on,
+        } => {
             let mut new_task = Task::new(task, due_date)?;
             new_task.priority = Some(prompt_for_priority()?);
             new_task.category = Some(prompt_for_category()?);
+            new_task.dependencies = depends_on.into_iter().collect();
             tasks::add_task(journal_file, new_task)
         }
         List {
             category,
             sort_order,
-        } => tasks::list_tasks(journal_file, category, sort_order),
-        Done { position } => tasks::complete_task(journal_file, position),
+            filter,
+            columns,
+            ready,
+            status,
+        } => tasks::list_tasks(
+            journal_file,
+            category,
+            sort_order,
+            filter,
+            columns,
+            ready,
+            status,
+        ),
+        Done { id } => tasks::complete_task(journal_file, id),
         Search { keyword } => tasks::search_tasks(journal_file, keyword),
+        Track { id, duration, date } => tasks::track_task(journal_file, id, duration, date),
+        Depend { id, depends_on } => tasks::depend_task(journal_file, id, depends_on),
+        Export { out } => tasks::export_journal(journal_file, out),
+        Import { bundle } => tasks::import_journal(journal_file, bundle),
+        Restore { id } => tasks::restore_task(journal_file, id),
     }?;
     Ok(())
 }
@@ -2,14 +2,23 @@ use chrono::TimeZone;
 use chrono::{
     serde::ts_seconds, serde::ts_seconds_option, DateTime, Datelike, Local, NaiveDate, Utc,
 };
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{Error, ErrorKind, Result, Seek, SeekFrom};
 use std::path::PathBuf;
 
+/// ANSI SGR codes used to dim and strike through completed tasks in terminal output.
+const DIM: &str = "\x1b[2m";
+const STRIKETHROUGH: &str = "\x1b[9m";
+const RESET: &str = "\x1b[0m";
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Task {
     #[serde(default)]
@@ -24,6 +33,31 @@ pub struct Task {
 
     pub priority: Option<String>,
     pub category: Option<String>,
+
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
+
+    #[serde(default)]
+    pub dependencies: HashSet<usize>,
+
+    #[serde(default, with = "ts_seconds_option")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl Default for Task {
+    fn default() -> Self {
+        Task {
+            id: 0,
+            text: String::new(),
+            created_at: Utc::now(),
+            due_date: None,
+            priority: None,
+            category: None,
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+            completed_at: None,
+        }
+    }
 }
 
 impl Task {
@@ -53,7 +87,10 @@ impl Task {
             due_date,
             priority: None, // Initialize priority as None
             category: None, // Initialize category as None
-                            // we'll just fill this later
+            // we'll just fill this later
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+            completed_at: None,
         })
     }
 
@@ -65,6 +102,37 @@ impl Task {
             _ => 4,
         }
     }
+
+    /// A Taskwarrior-style urgency score used to sort the list when `--sort-order urgency`
+    /// is requested: a weighted sum of priority, due-date proximity, and task age.
+    pub fn urgency(&self) -> f64 {
+        let priority_score = match self.priority.as_deref() {
+            Some("high") => 6.0,
+            Some("medium") => 3.9,
+            Some("low") => 1.8,
+            _ => 0.0,
+        };
+
+        let due_score = match self.due_date {
+            Some(due) => {
+                let days_until_due = (due - Utc::now()).num_hours() as f64 / 24.0;
+                if days_until_due <= 1.0 {
+                    12.0
+                } else if days_until_due >= 15.0 {
+                    0.0
+                } else {
+                    12.0 * (15.0 - days_until_due) / 14.0
+                }
+            }
+            None => 0.0,
+        };
+
+        let age_days = (Utc::now() - self.created_at).num_hours() as f64 / 24.0;
+        let age_score = (age_days / 14.0 * 2.0).clamp(0.0, 2.0);
+
+        // This crate has no tags field yet, so Taskwarrior's tag bonus contributes 0.0.
+        priority_score + due_score + age_score
+    }
 }
 
 impl Ord for Task {
@@ -87,6 +155,444 @@ impl PartialEq for Task {
 
 impl Eq for Task {}
 
+/// A span of logged time, normalized so `minutes` always stays below 60.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct Duration {
+    pub hours: u16,
+    pub minutes: u16,
+}
+
+impl Duration {
+    /// Builds a `Duration`, carrying any excess minutes into hours.
+    pub fn new(hours: u16, minutes: u16) -> Result<Duration> {
+        let total_minutes = hours as u32 * 60 + minutes as u32;
+        Ok(Duration {
+            hours: (total_minutes / 60) as u16,
+            minutes: (total_minutes % 60) as u16,
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.minutes >= 60 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Invalid duration: {} minutes is not a valid value (must be < 60)",
+                    self.minutes
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        Duration::new(self.hours + other.hours, self.minutes + other.minutes)
+            .expect("adding two normalized durations is always valid")
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}h{:02}m", self.hours, self.minutes)
+    }
+}
+
+/// A single logged span of time against a task.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TimeEntry {
+    pub logged_date: NaiveDate,
+    pub message: Option<String>,
+    pub duration: Duration,
+}
+
+fn total_logged(task: &Task) -> Duration {
+    task.time_entries
+        .iter()
+        .fold(Duration::new(0, 0).unwrap(), |total, entry| {
+            total + entry.duration
+        })
+}
+
+/// Parses a duration string like "1h30m", "2h", or "45m".
+fn parse_duration_str(input: &str) -> Result<Duration> {
+    let re = Regex::new(r"^(?:(\d+)h)?(?:(\d+)m)?$").unwrap();
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "Invalid duration format. Use e.g. '1h30m', '2h', or '45m'.",
+        )
+    };
+    let caps = re
+        .captures(input)
+        .filter(|c| c.get(1).is_some() || c.get(2).is_some())
+        .ok_or_else(invalid)?;
+
+    let hours: u16 = caps
+        .get(1)
+        .map_or(Ok(0), |m| m.as_str().parse())
+        .map_err(|_| invalid())?;
+    let minutes: u16 = caps
+        .get(2)
+        .map_or(Ok(0), |m| m.as_str().parse())
+        .map_err(|_| invalid())?;
+    Duration::new(hours, minutes)
+}
+
+/// Checks that every stored time entry still satisfies the `minutes < 60` invariant.
+fn validate_time_entries(tasks: &[Task]) -> Result<()> {
+    for task in tasks {
+        for entry in &task.time_entries {
+            entry.duration.validate()?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// Rejects `tasks` if following `dependencies` edges from any task leads back to itself.
+///
+/// Runs an iterative DFS that colors nodes white/gray/black; reaching a gray node is a
+/// back edge, i.e. a cycle.
+fn check_dependency_cycles(tasks: &[Task]) -> Result<()> {
+    let adjacency: HashMap<usize, HashSet<usize>> = tasks
+        .iter()
+        .map(|t| (t.id, t.dependencies.clone()))
+        .collect();
+    let mut color: HashMap<usize, Color> = adjacency.keys().map(|&id| (id, Color::White)).collect();
+
+    for start in adjacency.keys().copied().collect::<Vec<_>>() {
+        if color.get(&start) != Some(&Color::White) {
+            continue;
+        }
+
+        let mut path = vec![start];
+        let mut frames: Vec<std::vec::IntoIter<usize>> = vec![adjacency
+            .get(&start)
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_iter()];
+        color.insert(start, Color::Gray);
+
+        while let Some(frame) = frames.last_mut() {
+            match frame.next() {
+                Some(next) => match color.get(&next).copied().unwrap_or(Color::White) {
+                    Color::White => {
+                        path.push(next);
+                        color.insert(next, Color::Gray);
+                        frames.push(
+                            adjacency
+                                .get(&next)
+                                .cloned()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .collect::<Vec<_>>()
+                                .into_iter(),
+                        );
+                    }
+                    Color::Gray => {
+                        let cycle_start = path.iter().position(|&id| id == next).unwrap();
+                        let chain: Vec<String> = path[cycle_start..]
+                            .iter()
+                            .chain(std::iter::once(&next))
+                            .map(usize::to_string)
+                            .collect();
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Circular dependency detected: {}", chain.join(" -> ")),
+                        ));
+                    }
+                    Color::Black => {}
+                },
+                None => {
+                    frames.pop();
+                    let done = path.pop().unwrap();
+                    color.insert(done, Color::Black);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A task is ready once each dependency is either no longer present in the journal
+/// or has been completed.
+fn is_ready(task: &Task, present_ids: &HashSet<usize>, completed_ids: &HashSet<usize>) -> bool {
+    task.dependencies
+        .iter()
+        .all(|dep| !present_ids.contains(dep) || completed_ids.contains(dep))
+}
+
+/// A field that a `--filter` clause can constrain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Priority,
+    Due,
+    Category,
+    Created,
+}
+
+/// A comparison operator parsed from a `--filter` clause.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+/// The right-hand side of a `--filter` clause, once parsed.
+#[derive(Debug, Clone)]
+enum Value {
+    Text(String),
+    Date(NaiveDate),
+    RelativeDays(i64),
+}
+
+/// A single `field<op>value` clause from a `--filter` string.
+#[derive(Debug, Clone)]
+struct FilterClause {
+    field: Field,
+    op: Op,
+    value: Value,
+}
+
+/// Parses a comma-separated `--filter` string into clauses that are ANDed together.
+fn parse_filter(filter: &str) -> Result<Vec<FilterClause>> {
+    filter
+        .split(',')
+        .map(|clause| parse_filter_clause(clause.trim()))
+        .collect()
+}
+
+fn parse_filter_clause(clause: &str) -> Result<FilterClause> {
+    let (field_str, op, value_str) = if let Some(idx) = clause.find(">=") {
+        (&clause[..idx], Op::Ge, &clause[idx + 2..])
+    } else if let Some(idx) = clause.find("<=") {
+        (&clause[..idx], Op::Le, &clause[idx + 2..])
+    } else if let Some(idx) = clause.find(':') {
+        (&clause[..idx], Op::Eq, &clause[idx + 1..])
+    } else if let Some(idx) = clause.find('<') {
+        (&clause[..idx], Op::Lt, &clause[idx + 1..])
+    } else if let Some(idx) = clause.find('>') {
+        (&clause[..idx], Op::Gt, &clause[idx + 1..])
+    } else {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid filter clause: '{}'", clause),
+        ));
+    };
+
+    let field = match field_str {
+        "priority" => Field::Priority,
+        "due" => Field::Due,
+        "category" => Field::Category,
+        "created" => Field::Created,
+        other => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unknown filter field: '{}'", other),
+            ))
+        }
+    };
+    let value = parse_filter_value(field, value_str)?;
+
+    Ok(FilterClause { field, op, value })
+}
+
+fn parse_filter_value(field: Field, value_str: &str) -> Result<Value> {
+    match field {
+        Field::Priority | Field::Category => Ok(Value::Text(value_str.to_lowercase())),
+        Field::Due => match value_str.strip_suffix('d') {
+            Some(days) => Ok(Value::RelativeDays(parse_relative_days(days)?)),
+            None => {
+                let date = NaiveDate::parse_from_str(value_str, "%Y-%m-%d")
+                    .map_err(|e| Error::new(ErrorKind::InvalidInput, e.to_string()))?;
+                Ok(Value::Date(date))
+            }
+        },
+        Field::Created => Ok(Value::RelativeDays(parse_relative_days(
+            value_str.strip_suffix('d').unwrap_or(value_str),
+        )?)),
+    }
+}
+
+fn parse_relative_days(days: &str) -> Result<i64> {
+    days.parse().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid relative duration: '{}d'", days),
+        )
+    })
+}
+
+fn priority_rank(priority: Option<&str>) -> u8 {
+    match priority {
+        Some("high") => 1,
+        Some("medium") => 2,
+        Some("low") => 3,
+        _ => 4,
+    }
+}
+
+fn compare<T: PartialOrd>(a: T, b: T, op: Op) -> bool {
+    match op {
+        Op::Eq => a == b,
+        Op::Lt => a < b,
+        Op::Gt => a > b,
+        Op::Le => a <= b,
+        Op::Ge => a >= b,
+    }
+}
+
+fn matches_filter(task: &Task, clauses: &[FilterClause]) -> bool {
+    clauses.iter().all(|clause| matches_clause(task, clause))
+}
+
+fn matches_clause(task: &Task, clause: &FilterClause) -> bool {
+    match (clause.field, &clause.value) {
+        (Field::Priority, Value::Text(v)) => compare(
+            priority_rank(task.priority.as_deref()),
+            priority_rank(Some(v.as_str())),
+            clause.op,
+        ),
+        (Field::Category, Value::Text(v)) => match &task.category {
+            Some(c) => clause.op == Op::Eq && c.eq_ignore_ascii_case(v),
+            None => false,
+        },
+        (Field::Due, Value::Date(d)) => match task.due_date {
+            Some(due) => compare(due.date_naive(), *d, clause.op),
+            None => false,
+        },
+        (Field::Due, Value::RelativeDays(days)) => match task.due_date {
+            Some(due) => compare(
+                due.date_naive(),
+                Utc::now().date_naive() + chrono::Duration::days(*days),
+                clause.op,
+            ),
+            None => false,
+        },
+        // `created<Nd` means "created within the last N days", i.e. more recent than
+        // the cutoff, so the cutoff is compared against `created_at` rather than the
+        // other way around: `created<7d` becomes `cutoff < created_at`.
+        (Field::Created, Value::RelativeDays(days)) => compare(
+            Utc::now() - chrono::Duration::days(*days),
+            task.created_at,
+            clause.op,
+        ),
+        _ => false,
+    }
+}
+
+/// A column that can be selected via `--columns` when listing tasks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Column {
+    Id,
+    Text,
+    Created,
+    Due,
+    Priority,
+    Category,
+    Logged,
+    Urgency,
+}
+
+const DEFAULT_COLUMNS: [Column; 8] = [
+    Column::Id,
+    Column::Text,
+    Column::Created,
+    Column::Due,
+    Column::Priority,
+    Column::Category,
+    Column::Logged,
+    Column::Urgency,
+];
+
+fn parse_columns(columns: &str) -> Result<Vec<Column>> {
+    columns
+        .split(',')
+        .map(|c| match c.trim() {
+            "id" => Ok(Column::Id),
+            "text" => Ok(Column::Text),
+            "created" => Ok(Column::Created),
+            "due" => Ok(Column::Due),
+            "priority" => Ok(Column::Priority),
+            "category" => Ok(Column::Category),
+            "logged" => Ok(Column::Logged),
+            "urgency" => Ok(Column::Urgency),
+            other => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unknown column: '{}'", other),
+            )),
+        })
+        .collect()
+}
+
+fn column_header(column: Column) -> String {
+    match column {
+        Column::Id => format!("{:<5}", "ID"),
+        Column::Text => format!("{:<50}", "Task"),
+        Column::Created => format!("{:<20}", "Created At"),
+        Column::Due => format!("{:<20}", "Due Date"),
+        Column::Priority => format!("{:<25}", "Priority"),
+        Column::Category => format!("{:<25}", "Category"),
+        Column::Logged => format!("{:>10}", "Logged"),
+        Column::Urgency => format!("{:>8}", "Urgency"),
+    }
+}
+
+fn format_column(task: &Task, column: Column) -> String {
+    match column {
+        Column::Id => format!("{:<5}", task.id),
+        Column::Text => {
+            let text = format!("{:<50}", task.text);
+            if task.completed_at.is_some() {
+                format!("{}{}{}{}", DIM, STRIKETHROUGH, text, RESET)
+            } else {
+                text
+            }
+        }
+        Column::Created => format!(
+            "{:<20}",
+            task.created_at.with_timezone(&Local).format("%F %H:%M")
+        ),
+        Column::Due => format!(
+            "{:<20}",
+            task.due_date.map_or(String::new(), |d| d
+                .with_timezone(&Local)
+                .format("%F")
+                .to_string())
+        ),
+        Column::Priority => format!("{:^25}", task.priority.as_deref().unwrap_or("")),
+        Column::Category => format!("{:^25}", task.category.as_deref().unwrap_or("")),
+        Column::Logged => format!("{:>10}", total_logged(task).to_string()),
+        Column::Urgency => format!("{:>8.1}", task.urgency()),
+    }
+}
+
+fn print_header(columns: &[Column]) {
+    let header: Vec<String> = columns.iter().map(|c| column_header(*c)).collect();
+    println!("{}", header.join(" "));
+}
+
+fn format_row(task: &Task, columns: &[Column]) -> String {
+    let cells: Vec<String> = columns.iter().map(|c| format_column(task, *c)).collect();
+    cells.join(" ")
+}
+
 fn collect_tasks(mut file: &File) -> Result<Vec<Task>> {
     file.seek(SeekFrom::Start(0))?; // Rewind the file before.
     let tasks = match serde_json::from_reader(file) {
@@ -109,16 +615,78 @@ pub fn add_task(journal_path: PathBuf, mut task: Task) -> Result<()> {
     // Assign an id to the new task.
     task.id = tasks.len() + 1;
     tasks.push(task);
+    check_dependency_cycles(&tasks)?;
 
     // Sort the tasks by their priority.
     tasks.sort();
+    validate_time_entries(&tasks)?;
     // Write the updated tasks back to the file.
     file.set_len(0)?;
     serde_json::to_writer_pretty(file, &tasks)?;
     Ok(())
 }
 
-pub fn complete_task(journal_path: PathBuf, task_position: usize) -> Result<()> {
+/// Adds dependencies to an existing task, rejecting the edit if it would introduce a cycle.
+pub fn depend_task(journal_path: PathBuf, task_id: usize, depends_on: Vec<usize>) -> Result<()> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(journal_path)?;
+    let mut tasks = collect_tasks(&file)?;
+
+    {
+        let task = tasks
+            .iter_mut()
+            .find(|t| t.id == task_id)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Invalid Task ID"))?;
+        task.dependencies.extend(depends_on);
+    }
+    check_dependency_cycles(&tasks)?;
+
+    tasks.sort();
+    validate_time_entries(&tasks)?;
+    file.set_len(0)?;
+    serde_json::to_writer_pretty(file, &tasks)?;
+    Ok(())
+}
+
+/// Logs time against an existing task, identified by its id.
+pub fn track_task(
+    journal_path: PathBuf,
+    task_id: usize,
+    duration: String,
+    date: Option<String>,
+) -> Result<()> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(journal_path)?;
+    let mut tasks = collect_tasks(&file)?;
+
+    let duration = parse_duration_str(&duration)?;
+    let logged_date = match date {
+        Some(date_str) => NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, e.to_string()))?,
+        None => Utc::now().date_naive(),
+    };
+
+    let task = tasks
+        .iter_mut()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Invalid Task ID"))?;
+    task.time_entries.push(TimeEntry {
+        logged_date,
+        message: None,
+        duration,
+    });
+
+    validate_time_entries(&tasks)?;
+    file.set_len(0)?;
+    serde_json::to_writer_pretty(file, &tasks)?;
+    Ok(())
+}
+
+pub fn complete_task(journal_path: PathBuf, task_id: usize) -> Result<()> {
     // Open the file.
     let file = OpenOptions::new()
         .read(true)
@@ -128,14 +696,42 @@ pub fn complete_task(journal_path: PathBuf, task_position: usize) -> Result<()>
     // Consume file's contents as a vector of tasks.
     let mut tasks = collect_tasks(&file)?;
 
-    // Try to remove the task.
-    if task_position == 0 || task_position > tasks.len() {
-        return Err(Error::new(ErrorKind::InvalidInput, "Invalid Task ID"));
-    }
-    tasks.remove(task_position - 1);
+    // Mark the task done in place; it stays in the journal for history and `--status done`.
+    let task = tasks
+        .iter_mut()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Invalid Task ID"))?;
+    task.completed_at = Some(Utc::now());
 
     // Sort the tasks by their priority.
     tasks.sort();
+    validate_time_entries(&tasks)?;
+    // Write the modified task list back into the file.
+    file.set_len(0)?;
+    serde_json::to_writer_pretty(file, &tasks)?;
+    Ok(())
+}
+
+/// Clears a task's completion timestamp, returning it to the pending list.
+pub fn restore_task(journal_path: PathBuf, task_id: usize) -> Result<()> {
+    // Open the file.
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(journal_path)?;
+
+    // Consume file's contents as a vector of tasks.
+    let mut tasks = collect_tasks(&file)?;
+
+    let task = tasks
+        .iter_mut()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Invalid Task ID"))?;
+    task.completed_at = None;
+
+    // Sort the tasks by their priority.
+    tasks.sort();
+    validate_time_entries(&tasks)?;
     // Write the modified task list back into the file.
     file.set_len(0)?;
     serde_json::to_writer_pretty(file, &tasks)?;
@@ -146,7 +742,25 @@ pub fn list_tasks(
     journal_path: PathBuf,
     category: Option<String>,
     sort_order: String,
+    filter: Option<String>,
+    columns: Option<String>,
+    ready: bool,
+    status: String,
 ) -> Result<()> {
+    let status = status.to_lowercase();
+    match status.as_str() {
+        "pending" | "done" | "all" => {}
+        other => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unknown status: '{}'. Expected 'pending', 'done', or 'all'.",
+                    other
+                ),
+            ))
+        }
+    }
+
     // Open the file.
     let file = OpenOptions::new().read(true).open(journal_path)?;
     // Parse the file and collect the tasks.
@@ -155,26 +769,45 @@ pub fn list_tasks(
     // Sort tasks based on the sort_order parameter.
     match sort_order.as_str() {
         "desc" => tasks.sort_by(|a, b| b.cmp(a)),
+        "urgency" => tasks.sort_by(|a, b| {
+            b.urgency()
+                .partial_cmp(&a.urgency())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.cmp(b))
+        }),
         _ => tasks.sort_by(|a, b| a.cmp(b)),
     }
 
+    let clauses = filter.as_deref().map(parse_filter).transpose()?;
+    let columns = columns.as_deref().map(parse_columns).transpose()?;
+    let columns = columns.as_deref().unwrap_or(&DEFAULT_COLUMNS);
+    let present_ids: HashSet<usize> = tasks.iter().map(|t| t.id).collect();
+    let completed_ids: HashSet<usize> = tasks
+        .iter()
+        .filter(|t| t.completed_at.is_some())
+        .map(|t| t.id)
+        .collect();
+
     // Enumerate and display tasks, if any.
     if tasks.is_empty() {
         println!("Task list is empty!");
     } else {
-        // Print the headers.
-        println!(
-            "{:<5} {:<50} {:<20} {:<20} {:<25} {:<25}",
-            "ID", "Task", "Created At", "Due Date", "Priority", "Category"
-        );
-        let mut order: u32 = 1;
+        print_header(columns);
         for task in tasks {
-            if category
+            let category_match = category
+                .as_ref()
+                .map_or(true, |c| task.category.as_ref() == Some(c));
+            let filter_match = clauses
                 .as_ref()
-                .map_or(true, |c| task.category.as_ref() == Some(c))
-            {
-                println!("{}: {}", order, task);
-                order += 1;
+                .map_or(true, |clauses| matches_filter(&task, clauses));
+            let ready_match = !ready || is_ready(&task, &present_ids, &completed_ids);
+            let status_match = match status.as_str() {
+                "done" => task.completed_at.is_some(),
+                "all" => true,
+                _ => task.completed_at.is_none(),
+            };
+            if category_match && filter_match && ready_match && status_match {
+                println!("{}", format_row(&task, columns));
             }
         }
     }
@@ -200,8 +833,8 @@ pub fn search_tasks(journal_path: PathBuf, keyword: String) -> Result<()> {
     } else {
         // Print the headers.
         println!(
-            "{:<5} {:<50} {:<20} {:<20} {:<25} {:<25}",
-            "ID", "Task", "Created At", "Due Date", "Priority", "Category"
+            "{:<5} {:<50} {:<20} {:<20} {:<25} {:<25} {:>10}",
+            "ID", "Task", "Created At", "Due Date", "Priority", "Category", "Logged"
         );
         for task in filtered_tasks {
             println!("{}", task);
@@ -211,21 +844,133 @@ pub fn search_tasks(journal_path: PathBuf, keyword: String) -> Result<()> {
     Ok(())
 }
 
+/// The current on-disk layout version written into a bundle's `metadata.json`.
+const DUMP_VERSION: u32 = 1;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BundleMetadata {
+    dump_version: u32,
+    crate_version: String,
+    #[serde(with = "ts_seconds")]
+    exported_at: DateTime<Utc>,
+}
+
+/// Writes the journal to a self-describing, gzip-compressed tar bundle: a `metadata.json`
+/// (dump version, crate version, export time) alongside a `tasks.json` snapshot.
+pub fn export_journal(journal_path: PathBuf, out: PathBuf) -> Result<()> {
+    let file = OpenOptions::new().read(true).open(journal_path)?;
+    let tasks = collect_tasks(&file)?;
+
+    let metadata = BundleMetadata {
+        dump_version: DUMP_VERSION,
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+    };
+
+    let tmp_dir = std::env::temp_dir().join(format!("rusty-journal-export-{}", std::process::id()));
+    fs::create_dir_all(&tmp_dir)?;
+
+    let metadata_path = tmp_dir.join("metadata.json");
+    serde_json::to_writer_pretty(File::create(&metadata_path)?, &metadata)?;
+    let tasks_path = tmp_dir.join("tasks.json");
+    serde_json::to_writer_pretty(File::create(&tasks_path)?, &tasks)?;
+
+    let mut builder =
+        tar::Builder::new(GzEncoder::new(File::create(&out)?, Compression::default()));
+    builder.append_path_with_name(&metadata_path, "metadata.json")?;
+    builder.append_path_with_name(&tasks_path, "tasks.json")?;
+    builder.into_inner()?.finish()?;
+
+    fs::remove_dir_all(&tmp_dir)?;
+    Ok(())
+}
+
+/// Reads a bundle produced by [`export_journal`] and merges its tasks into `journal_path`,
+/// reassigning ids to avoid colliding with what's already there and remapping
+/// `dependencies` so they keep pointing at the same (now renumbered) imported tasks.
+///
+/// Tasks written by an older layout simply deserialize with `#[serde(default)]` filling in
+/// whichever of `time_entries`/`dependencies`/`id` they were missing.
+pub fn import_journal(journal_path: PathBuf, bundle: PathBuf) -> Result<()> {
+    let mut archive = tar::Archive::new(GzDecoder::new(File::open(&bundle)?));
+
+    let mut metadata: Option<BundleMetadata> = None;
+    let mut imported_tasks: Vec<Task> = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        match entry.path()?.file_name().and_then(|n| n.to_str()) {
+            Some("metadata.json") => metadata = Some(serde_json::from_reader(&mut entry)?),
+            Some("tasks.json") => imported_tasks = serde_json::from_reader(&mut entry)?,
+            _ => {}
+        }
+    }
+
+    let metadata = metadata
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Bundle is missing metadata.json"))?;
+    if metadata.dump_version > DUMP_VERSION {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Bundle dump_version {} is newer than the supported version {}",
+                metadata.dump_version, DUMP_VERSION
+            ),
+        ));
+    }
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .open(journal_path)?;
+    let mut tasks = collect_tasks(&file)?;
+
+    // Map the imported tasks' old ids to their freshly assigned ones so dependencies
+    // referencing each other (rather than tasks outside the bundle) still resolve.
+    let base_id = tasks.len();
+    let id_map: HashMap<usize, usize> = imported_tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| (task.id, base_id + i + 1))
+        .collect();
+
+    for (i, mut task) in imported_tasks.into_iter().enumerate() {
+        task.id = base_id + i + 1;
+        task.dependencies = task
+            .dependencies
+            .iter()
+            .filter_map(|dep| id_map.get(dep).copied())
+            .collect();
+        tasks.push(task);
+    }
+
+    tasks.sort();
+    check_dependency_cycles(&tasks)?;
+    validate_time_entries(&tasks)?;
+    file.set_len(0)?;
+    serde_json::to_writer_pretty(file, &tasks)?;
+    Ok(())
+}
+
 impl fmt::Display for Task {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let created_at = self.created_at.with_timezone(&Local).format("%F %H:%M");
         let due_date = self.due_date.map_or("".to_string(), |d| {
             d.with_timezone(&Local).format("%F").to_string()
         });
-        write!(
-            f,
-            "{:<50} {:<20} {:^15} {:^25} {:^25}",
+        let row = format!(
+            "{:<50} {:<20} {:^15} {:^25} {:^25} {:>10}",
             self.text,
             created_at,
             due_date,
             self.priority.as_ref().unwrap_or(&"".to_string()),
-            self.category.as_ref().unwrap_or(&"".to_string())
-        )
+            self.category.as_ref().unwrap_or(&"".to_string()),
+            total_logged(self).to_string()
+        );
+        if self.completed_at.is_some() {
+            write!(f, "{}{}{}{}", DIM, STRIKETHROUGH, row, RESET)
+        } else {
+            write!(f, "{}", row)
+        }
     }
 }
 
@@ -265,6 +1010,7 @@ mod tests {
             due_date: None,
             priority: Some(String::from("high")),
             category: None,
+            ..Default::default()
         };
 
         assert_eq!(task.priority_order(), 1);
@@ -279,6 +1025,7 @@ mod tests {
             due_date: None,
             priority: Some(String::from("medium")),
             category: None,
+            ..Default::default()
         };
 
         assert_eq!(task.priority_order(), 2);
@@ -293,6 +1040,7 @@ mod tests {
             due_date: None,
             priority: Some(String::from("low")),
             category: None,
+            ..Default::default()
         };
 
         assert_eq!(task.priority_order(), 3);
@@ -307,6 +1055,7 @@ mod tests {
             due_date: None,
             priority: None,
             category: None,
+            ..Default::default()
         };
 
         assert_eq!(task.priority_order(), 4);
@@ -321,6 +1070,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("I don't know")),
             priority: Some(String::from("I don't know either")),
+            ..Default::default()
         };
 
         // Serialize the task to JSON as an array
@@ -366,6 +1116,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Test Category")),
             priority: Some(String::from("high")),
+            ..Default::default()
         };
 
         // Call the add_task function
@@ -399,6 +1150,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 1")),
             priority: Some(String::from("high")),
+            ..Default::default()
         };
 
         let task2 = Task {
@@ -408,6 +1160,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 2")),
             priority: Some(String::from("medium")),
+            ..Default::default()
         };
 
         let tasks = vec![task1.clone(), task2.clone()];
@@ -418,17 +1171,24 @@ mod tests {
         file.set_len(0)?;
         file.write_all(json.as_bytes())?;
 
-        // Call the complete_task function to remove the first task
+        // Call the complete_task function to mark the first task done
         complete_task(path.clone(), 1)?;
 
-        // Read the file and verify the task was removed
+        // Read the file and verify the task is still present, but marked complete
         let file = File::open(&path)?;
         let remaining_tasks: Vec<Task> = serde_json::from_reader(file)?;
 
-        assert_eq!(remaining_tasks.len(), 1);
-        assert_eq!(remaining_tasks[0].text, task2.text);
-        assert_eq!(remaining_tasks[0].category, task2.category);
-        assert_eq!(remaining_tasks[0].priority, task2.priority);
+        assert_eq!(remaining_tasks.len(), 2);
+        let done = remaining_tasks
+            .iter()
+            .find(|t| t.text == task1.text)
+            .unwrap();
+        assert!(done.completed_at.is_some());
+        let still_pending = remaining_tasks
+            .iter()
+            .find(|t| t.text == task2.text)
+            .unwrap();
+        assert_eq!(still_pending.completed_at, None);
 
         // Clean up the temporary file
         remove_file(&path)?;
@@ -436,6 +1196,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_complete_task_targets_id_not_sorted_position() -> Result<()> {
+        // Tasks are stored sorted by priority, so a task's array slot does not match
+        // its id once priorities differ. complete_task must key off of id, like
+        // track_task and depend_task already do.
+        let path = PathBuf::from("temp_journal_complete_task_by_id.json");
+
+        let low = Task {
+            id: 1,
+            text: String::from("Low"),
+            created_at: Utc::now(),
+            priority: Some(String::from("low")),
+            ..Default::default()
+        };
+        let high = Task {
+            id: 2,
+            text: String::from("High"),
+            created_at: Utc::now(),
+            priority: Some(String::from("high")),
+            ..Default::default()
+        };
+
+        // Written sorted by priority, so "High" (id 2) occupies array slot 0.
+        let tasks = vec![high.clone(), low.clone()];
+        let json = serde_json::to_string(&tasks)?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        complete_task(path.clone(), 1)?;
+
+        let file = File::open(&path)?;
+        let tasks: Vec<Task> = serde_json::from_reader(file)?;
+        let low = tasks.iter().find(|t| t.id == 1).unwrap();
+        let high = tasks.iter().find(|t| t.id == 2).unwrap();
+        assert!(low.completed_at.is_some());
+        assert_eq!(high.completed_at, None);
+
+        remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore_task_clears_completed_at() -> Result<()> {
+        let path = PathBuf::from("temp_journal_restore_task.json");
+
+        let task = Task {
+            id: 1,
+            text: String::from("Task 1"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&vec![task])?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        complete_task(path.clone(), 1)?;
+        restore_task(path.clone(), 1)?;
+
+        let file = File::open(&path)?;
+        let tasks: Vec<Task> = serde_json::from_reader(file)?;
+        assert_eq!(tasks[0].completed_at, None);
+
+        remove_file(&path)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_tasks_returns_ok() -> Result<()> {
         // Define the path for the temporary JSON file
@@ -449,6 +1280,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 1")),
             priority: Some(String::from("high")),
+            ..Default::default()
         };
 
         let task2 = Task {
@@ -458,6 +1290,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 2")),
             priority: Some(String::from("medium")),
+            ..Default::default()
         };
 
         let tasks = vec![task1.clone(), task2.clone()];
@@ -473,6 +1306,10 @@ mod tests {
             path.clone(),
             Some(String::from("Category 1")),
             String::from("asc"),
+            None,
+            None,
+            false,
+            String::from("pending"),
         );
         assert!(result.is_ok());
 
@@ -481,6 +1318,108 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_list_tasks_rejects_unknown_status() -> Result<()> {
+        let path = PathBuf::from("temp_journal_list_tasks_status.json");
+
+        let json = serde_json::to_string(&Vec::<Task>::new())?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        let result = list_tasks(
+            path.clone(),
+            None,
+            String::from("asc"),
+            None,
+            None,
+            false,
+            String::from("archived"),
+        );
+        assert!(result.is_err());
+
+        remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_filter_priority_and_category() {
+        let clauses = parse_filter("priority:high,category:work").unwrap();
+        assert_eq!(clauses.len(), 2);
+
+        let task = Task {
+            id: 1,
+            text: String::from("Task"),
+            created_at: Utc::now(),
+            due_date: None,
+            priority: Some(String::from("high")),
+            category: Some(String::from("work")),
+            ..Default::default()
+        };
+        assert!(matches_filter(&task, &clauses));
+
+        let other = Task {
+            priority: Some(String::from("low")),
+            ..task
+        };
+        assert!(!matches_filter(&other, &clauses));
+    }
+
+    #[test]
+    fn test_parse_filter_due_date_comparisons() {
+        let clauses = parse_filter("due<2024-12-31").unwrap();
+        let task = Task {
+            id: 1,
+            text: String::from("Task"),
+            created_at: Utc::now(),
+            due_date: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            priority: None,
+            category: None,
+            ..Default::default()
+        };
+        assert!(matches_filter(&task, &clauses));
+    }
+
+    #[test]
+    fn test_parse_filter_created_within_days_matches_recent_not_old() {
+        let clauses = parse_filter("created<7d").unwrap();
+
+        let recent = Task {
+            created_at: Utc::now() - chrono::Duration::days(2),
+            ..Default::default()
+        };
+        let old = Task {
+            created_at: Utc::now() - chrono::Duration::days(20),
+            ..Default::default()
+        };
+
+        assert!(matches_filter(&recent, &clauses));
+        assert!(!matches_filter(&old, &clauses));
+    }
+
+    #[test]
+    fn test_parse_filter_rejects_unknown_field() {
+        assert!(parse_filter("nope:value").is_err());
+    }
+
+    #[test]
+    fn test_parse_columns_rejects_unknown_column() {
+        assert!(parse_columns("id,bogus").is_err());
+    }
+
+    #[test]
+    fn test_id_column_shows_real_task_id_not_display_order() {
+        let task = Task {
+            id: 7,
+            text: String::from("Task"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        assert_eq!(format_column(&task, Column::Id), format!("{:<5}", 7));
+    }
+
     #[test]
     fn test_search_tasks_returns_ok() -> Result<()> {
         // Define the path for the temporary JSON file
@@ -494,6 +1433,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 1")),
             priority: Some(String::from("high")),
+            ..Default::default()
         };
 
         let task2 = Task {
@@ -503,6 +1443,7 @@ mod tests {
             due_date: Some(Utc::now() + chrono::Duration::days(7)),
             category: Some(String::from("Category 2")),
             priority: Some(String::from("medium")),
+            ..Default::default()
         };
 
         let tasks = vec![task1.clone(), task2.clone()];
@@ -522,4 +1463,348 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_duration_normalizes_excess_minutes() {
+        let duration = Duration::new(1, 90).unwrap();
+        assert_eq!(duration.hours, 2);
+        assert_eq!(duration.minutes, 30);
+    }
+
+    #[test]
+    fn test_parse_duration_str_variants() {
+        assert_eq!(
+            parse_duration_str("1h30m").unwrap(),
+            Duration::new(1, 30).unwrap()
+        );
+        assert_eq!(
+            parse_duration_str("2h").unwrap(),
+            Duration::new(2, 0).unwrap()
+        );
+        assert_eq!(
+            parse_duration_str("45m").unwrap(),
+            Duration::new(0, 45).unwrap()
+        );
+        assert!(parse_duration_str("bogus").is_err());
+    }
+
+    #[test]
+    fn test_track_task_appends_time_entry() -> Result<()> {
+        let path = PathBuf::from("temp_journal_track_task.json");
+
+        let task = Task {
+            id: 1,
+            text: String::from("Task 1"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&vec![task])?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        track_task(
+            path.clone(),
+            1,
+            String::from("1h30m"),
+            Some(String::from("2024-01-01")),
+        )?;
+
+        let file = File::open(&path)?;
+        let tasks: Vec<Task> = serde_json::from_reader(file)?;
+        assert_eq!(tasks[0].time_entries.len(), 1);
+        assert_eq!(
+            tasks[0].time_entries[0].duration,
+            Duration::new(1, 30).unwrap()
+        );
+        assert_eq!(total_logged(&tasks[0]), Duration::new(1, 30).unwrap());
+
+        remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_track_task_rejects_unknown_id() {
+        let path = PathBuf::from("temp_journal_track_task_missing.json");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&path)
+            .unwrap();
+        file.set_len(0).unwrap();
+        file.write_all(b"[]").unwrap();
+
+        let result = track_task(path.clone(), 1, String::from("1h"), None);
+        assert!(result.is_err());
+
+        remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_check_dependency_cycles_detects_back_edge() {
+        let task1 = Task {
+            id: 1,
+            text: String::from("Task 1"),
+            dependencies: HashSet::from([2]),
+            ..Default::default()
+        };
+        let task2 = Task {
+            id: 2,
+            text: String::from("Task 2"),
+            dependencies: HashSet::from([1]),
+            ..Default::default()
+        };
+
+        assert!(check_dependency_cycles(&[task1, task2]).is_err());
+    }
+
+    #[test]
+    fn test_check_dependency_cycles_allows_dag() {
+        let task1 = Task {
+            id: 1,
+            text: String::from("Task 1"),
+            dependencies: HashSet::from([2]),
+            ..Default::default()
+        };
+        let task2 = Task {
+            id: 2,
+            text: String::from("Task 2"),
+            ..Default::default()
+        };
+
+        assert!(check_dependency_cycles(&[task1, task2]).is_ok());
+    }
+
+    #[test]
+    fn test_depend_task_rejects_cycle() -> Result<()> {
+        let path = PathBuf::from("temp_journal_depend_task_cycle.json");
+
+        let task1 = Task {
+            id: 1,
+            text: String::from("Task 1"),
+            ..Default::default()
+        };
+        let task2 = Task {
+            id: 2,
+            text: String::from("Task 2"),
+            dependencies: HashSet::from([1]),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&vec![task1, task2])?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        let result = depend_task(path.clone(), 1, vec![2]);
+        assert!(result.is_err());
+
+        remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_ready_hides_blocked_tasks() {
+        let blocked = Task {
+            id: 1,
+            dependencies: HashSet::from([2]),
+            ..Default::default()
+        };
+        let present_ids = HashSet::from([1, 2]);
+        let completed_ids = HashSet::new();
+        assert!(!is_ready(&blocked, &present_ids, &completed_ids));
+
+        let unblocked = Task {
+            id: 1,
+            dependencies: HashSet::from([2]),
+            ..Default::default()
+        };
+        let present_ids = HashSet::from([1]);
+        assert!(is_ready(&unblocked, &present_ids, &completed_ids));
+    }
+
+    #[test]
+    fn test_is_ready_allows_completed_dependency() {
+        let task = Task {
+            id: 1,
+            dependencies: HashSet::from([2]),
+            ..Default::default()
+        };
+        let present_ids = HashSet::from([1, 2]);
+        let completed_ids = HashSet::from([2]);
+        assert!(is_ready(&task, &present_ids, &completed_ids));
+    }
+
+    #[test]
+    fn test_urgency_overdue_task_scores_near_max() {
+        let task = Task {
+            priority: Some(String::from("high")),
+            due_date: Some(Utc::now() - chrono::Duration::days(1)),
+            ..Default::default()
+        };
+
+        assert!(task.urgency() >= 6.0 + 12.0);
+    }
+
+    #[test]
+    fn test_urgency_far_out_due_date_contributes_nothing() {
+        let with_distant_due = Task {
+            due_date: Some(Utc::now() + chrono::Duration::days(30)),
+            ..Default::default()
+        };
+        let without_due = Task {
+            ..Default::default()
+        };
+
+        assert!((with_distant_due.urgency() - without_due.urgency()).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_urgency_higher_priority_scores_higher() {
+        let high = Task {
+            priority: Some(String::from("high")),
+            ..Default::default()
+        };
+        let low = Task {
+            priority: Some(String::from("low")),
+            ..Default::default()
+        };
+
+        assert!(high.urgency() > low.urgency());
+    }
+
+    #[test]
+    fn test_export_then_import_round_trips_tasks() -> Result<()> {
+        let journal_path = PathBuf::from("temp_journal_export_source.json");
+        let bundle_path = PathBuf::from("temp_journal_export_bundle.tar.gz");
+        let target_path = PathBuf::from("temp_journal_export_target.json");
+
+        let task = Task {
+            id: 1,
+            text: String::from("Exported task"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&vec![task])?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&journal_path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        export_journal(journal_path.clone(), bundle_path.clone())?;
+        import_journal(target_path.clone(), bundle_path.clone())?;
+
+        let file = File::open(&target_path)?;
+        let imported: Vec<Task> = serde_json::from_reader(file)?;
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].text, "Exported task");
+        assert_eq!(imported[0].id, 1);
+
+        remove_file(&journal_path)?;
+        remove_file(&bundle_path)?;
+        remove_file(&target_path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_remaps_dependencies_to_new_ids() -> Result<()> {
+        let journal_path = PathBuf::from("temp_journal_export_deps_source.json");
+        let bundle_path = PathBuf::from("temp_journal_export_deps_bundle.tar.gz");
+        let target_path = PathBuf::from("temp_journal_export_deps_target.json");
+
+        // T1 has old id 1, T2 has old id 2 and depends on T1.
+        let t1 = Task {
+            id: 1,
+            text: String::from("T1"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        let t2 = Task {
+            id: 2,
+            text: String::from("T2"),
+            created_at: Utc::now(),
+            dependencies: HashSet::from([1]),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&vec![t1, t2])?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&journal_path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+        export_journal(journal_path.clone(), bundle_path.clone())?;
+
+        // The target journal already has an unrelated task at old id 1, so the import
+        // must renumber T1/T2 and rewrite T2's dependency to follow T1's new id.
+        let unrelated = Task {
+            id: 1,
+            text: String::from("Unrelated"),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&vec![unrelated])?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&target_path)?;
+        file.set_len(0)?;
+        file.write_all(json.as_bytes())?;
+
+        import_journal(target_path.clone(), bundle_path.clone())?;
+
+        let file = File::open(&target_path)?;
+        let tasks: Vec<Task> = serde_json::from_reader(file)?;
+        let new_t1 = tasks.iter().find(|t| t.text == "T1").unwrap();
+        let new_t2 = tasks.iter().find(|t| t.text == "T2").unwrap();
+        assert_eq!(new_t2.dependencies, HashSet::from([new_t1.id]));
+        assert_ne!(
+            new_t1.id, 1,
+            "T1 should have been renumbered past the unrelated task"
+        );
+
+        remove_file(&journal_path)?;
+        remove_file(&bundle_path)?;
+        remove_file(&target_path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_rejects_bundle_from_a_newer_version() -> Result<()> {
+        let bundle_path = PathBuf::from("temp_journal_import_future.tar.gz");
+        let target_path = PathBuf::from("temp_journal_import_future_target.json");
+
+        let tmp_dir = std::env::temp_dir().join("rusty-journal-test-future-bundle");
+        fs::create_dir_all(&tmp_dir)?;
+        let metadata = BundleMetadata {
+            dump_version: DUMP_VERSION + 1,
+            crate_version: String::from("0.0.0"),
+            exported_at: Utc::now(),
+        };
+        let metadata_path = tmp_dir.join("metadata.json");
+        serde_json::to_writer_pretty(File::create(&metadata_path)?, &metadata)?;
+        let tasks_path = tmp_dir.join("tasks.json");
+        serde_json::to_writer_pretty(File::create(&tasks_path)?, &Vec::<Task>::new())?;
+
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(&bundle_path)?,
+            Compression::default(),
+        ));
+        builder.append_path_with_name(&metadata_path, "metadata.json")?;
+        builder.append_path_with_name(&tasks_path, "tasks.json")?;
+        builder.into_inner()?.finish()?;
+
+        let result = import_journal(target_path.clone(), bundle_path.clone());
+        assert!(result.is_err());
+
+        fs::remove_dir_all(&tmp_dir)?;
+        remove_file(&bundle_path)?;
+
+        Ok(())
+    }
 }